@@ -0,0 +1,46 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use mpz_core::Block;
+use mpz_garble_core::{encoding::Labels, encoding_state::Full, Delta, Label};
+use rand::SeedableRng;
+use rand_chacha::ChaCha12Rng;
+
+fn labels(rng: &mut ChaCha12Rng, delta: Delta) -> Labels<1024, Full> {
+    Labels::<1024, Full>::new(
+        delta,
+        std::array::from_fn(|_| Label::new(Block::random(rng))),
+    )
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("xor");
+
+    let mut rng = ChaCha12Rng::seed_from_u64(0);
+    let delta = Delta::random(&mut rng);
+    let a = labels(&mut rng, delta);
+    let b = labels(&mut rng, delta);
+
+    // The previous `BitXor` implementation, which builds the result one label at a time
+    // with `std::array::from_fn` and moves it into a new `Arc`.
+    group.bench_function("xor_1024_labels_from_fn", |bench| {
+        bench.iter(|| {
+            black_box(Labels::<1024, Full>::new(
+                delta,
+                std::array::from_fn(|i| a[i] ^ b[i]),
+            ))
+        })
+    });
+
+    group.bench_function("xor_1024_labels", |bench| bench.iter(|| black_box(&a ^ &b)));
+
+    // A fresh, unshared lhs for every iteration so the owned XOR can write in place.
+    group.bench_function("xor_1024_labels_owned", |bench| {
+        bench.iter_batched(
+            || (labels(&mut rng, delta), b.clone()),
+            |(a, b)| black_box(a ^ b),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
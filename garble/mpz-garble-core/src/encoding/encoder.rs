@@ -1,13 +1,22 @@
 use mpz_circuits::types::{BinaryLength, ValueType};
-use mpz_core::Block;
+use mpz_core::{hash::DomainSeparatedHash, impl_domain_separated_hash, Block};
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha20Rng;
 use rand_core::OsRng;
+use serde::Serialize;
 
 use super::{state, value::Encode, Delta, EncodedValue, Label};
 
 const DELTA_STREAM_ID: u64 = u64::MAX;
 
+#[derive(Serialize)]
+struct ForkSeed {
+    seed: [u8; 32],
+    nonce: u64,
+}
+
+impl_domain_separated_hash!(ForkSeed, "ENCODER_FORK");
+
 /// This trait is used to encode values using a global offset (delta).
 ///
 /// Implementations of this trait should be _idempotent_, meaning that calling
@@ -21,6 +30,17 @@ pub trait Encoder: Send + Sync {
     /// Returns encoder's global offset
     fn delta(&self) -> Delta;
 
+    /// Returns a new encoder derived from this encoder and the provided nonce.
+    ///
+    /// Forks with distinct nonces produce independent encodings, including their own
+    /// global offset, so a single seed can be used to encode values for multiple
+    /// garbled circuit executions without re-using labels.
+    ///
+    /// * `nonce` - Unique nonce of the fork
+    fn fork(&self, nonce: u64) -> Self
+    where
+        Self: Sized;
+
     /// Encodes a type using the provided stream id
     ///
     /// * `id` - Unique id of value
@@ -86,6 +106,16 @@ impl Encoder for ChaChaEncoder {
         self.delta
     }
 
+    fn fork(&self, nonce: u64) -> Self {
+        let seed = ForkSeed {
+            seed: self.seed,
+            nonce,
+        }
+        .domain_separated_hash();
+
+        Self::new(*seed.as_bytes())
+    }
+
     fn encode<T: Encode + BinaryLength>(&self, id: u64) -> T::Encoded {
         let mut rng = self.get_rng(id);
 
@@ -152,4 +182,32 @@ mod test {
 
         assert_eq!(encoded, encoded2);
     }
+
+    #[rstest]
+    fn test_encoder_fork(encoder: ChaChaEncoder) {
+        let fork_0 = encoder.fork(0);
+        let fork_1 = encoder.fork(1);
+
+        assert_eq!(encoder.fork(0).seed(), fork_0.seed());
+        assert_ne!(fork_0.seed(), fork_1.seed());
+        assert_ne!(fork_0.delta(), fork_1.delta());
+
+        // Low and high labels of the same value id from each encoder.
+        let labels: Vec<Vec<Block>> = [&encoder, &fork_0, &fork_1]
+            .iter()
+            .map(|encoder| {
+                encoder
+                    .encode::<[u8; 16]>(0)
+                    .iter_blocks()
+                    .flatten()
+                    .collect()
+            })
+            .collect();
+
+        for (i, a) in labels.iter().enumerate() {
+            for b in labels.iter().skip(i + 1) {
+                assert!(a.iter().all(|label| !b.contains(label)));
+            }
+        }
+    }
 }
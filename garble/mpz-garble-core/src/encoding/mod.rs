@@ -25,6 +25,7 @@ use std::{
 use mpz_core::Block;
 use rand::{CryptoRng, Rng};
 use serde::{Deserialize, Deserializer, Serialize};
+use subtle::{Choice, ConstantTimeEq};
 
 pub use encoder::{ChaChaEncoder, Encoder};
 pub use equality::EqualityCheck;
@@ -39,13 +40,25 @@ pub use value::{Decoding, Encode, EncodedValue, EncodingCommitment, ValueError};
 pub struct Delta(Block);
 
 impl Delta {
-    /// Creates new random Delta
-    pub fn random<R: Rng + CryptoRng + ?Sized>(rng: &mut R) -> Self {
-        let mut block = Block::random(rng);
+    /// Creates a new Delta from the provided block.
+    ///
+    /// The LSB of the block is set to 1, regardless of its value in `block`.
+    pub fn new(mut block: Block) -> Self {
         block.set_lsb();
         Self(block)
     }
 
+    /// Creates new random Delta
+    pub fn random<R: Rng + CryptoRng + ?Sized>(rng: &mut R) -> Self {
+        Self::new(Block::random(rng))
+    }
+
+    /// Returns a reference to the inner block
+    #[inline]
+    pub fn as_block(&self) -> &Block {
+        &self.0
+    }
+
     /// Returns the inner block
     #[inline]
     pub(crate) fn into_inner(self) -> Block {
@@ -73,7 +86,7 @@ pub mod state {
     }
 
     /// Marker trait for label state
-    pub trait LabelState: sealed::Sealed + Clone {}
+    pub trait LabelState: sealed::Sealed + Clone + PartialEq {}
 
     /// Full label state, ie contains both the low and high labels.
     #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -131,13 +144,89 @@ where
     /// When the labels are in the `Full` state, the iterator will return the low labels.
     ///
     /// When the labels are in the `Active` state, the iterator will return the active labels.
+    ///
+    /// Prefer [`Labels::iter_low`], [`Labels::iter_high`] or [`Labels::iter_active`], which
+    /// make the intended labels explicit.
     pub fn iter(&self) -> impl Iterator<Item = &Label> {
         self.labels.iter()
     }
+
+    /// Splits the labels into two collections containing the first `A` and the last `B`
+    /// labels respectively.
+    ///
+    /// Fails to compile if `A + B != N`.
+    pub fn split<const A: usize, const B: usize>(&self) -> (Labels<A, S>, Labels<B, S>) {
+        let () = LengthCheck::<A, B, N>::SUM;
+
+        let (a, b) = self.labels.split_at(A);
+
+        (
+            Labels {
+                state: self.state.clone(),
+                labels: Arc::new(a.try_into().expect("length should match")),
+            },
+            Labels {
+                state: self.state.clone(),
+                labels: Arc::new(b.try_into().expect("length should match")),
+            },
+        )
+    }
+
+    /// Concatenates two collections of labels.
+    ///
+    /// Fails to compile if `A + B != N`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the collections are not in the same state, eg. full labels with
+    /// different deltas.
+    pub fn concat<const A: usize, const B: usize>(
+        a: &Labels<A, S>,
+        b: &Labels<B, S>,
+    ) -> Result<Self, ValueError> {
+        let () = LengthCheck::<A, B, N>::SUM;
+        if a.state != b.state {
+            return Err(ValueError::StateMismatch);
+        }
+
+        let mut labels = a.labels.iter().chain(b.labels.iter()).copied();
+
+        Ok(Self {
+            state: a.state.clone(),
+            labels: Arc::new(std::array::from_fn(|_| {
+                labels.next().expect("length should match")
+            })),
+        })
+    }
+
+    /// XORs `rhs` into these labels.
+    ///
+    /// The underlying allocation is re-used if it is not shared with another collection.
+    #[inline]
+    fn xor_in_place(mut self, rhs: &Self) -> Self {
+        for (lhs, rhs) in Arc::make_mut(&mut self.labels)
+            .iter_mut()
+            .zip(rhs.labels.iter())
+        {
+            lhs.0 ^= rhs.0;
+        }
+        self
+    }
+}
+
+/// Compile-time check that `A + B == N`, evaluated wherever `SUM` is referenced.
+struct LengthCheck<const A: usize, const B: usize, const N: usize>;
+
+impl<const A: usize, const B: usize, const N: usize> LengthCheck<A, B, N> {
+    const SUM: () = assert!(A + B == N, "label lengths must satisfy A + B == N");
 }
 
 impl<const N: usize> Labels<N, state::Full> {
-    pub(crate) fn new(delta: Delta, labels: [Label; N]) -> Self {
+    /// Creates a new collection of full labels from the low labels and the global offset
+    /// `delta`.
+    ///
+    /// The high label of each bit is its low label XORed with `delta`.
+    pub fn new(delta: Delta, labels: [Label; N]) -> Self {
         Self {
             state: state::Full { delta },
             labels: Arc::new(labels),
@@ -148,15 +237,32 @@ impl<const N: usize> Labels<N, state::Full> {
         self.state.delta
     }
 
+    /// Returns an iterator over the low labels, ie the labels encoding the bit value 0.
+    pub fn iter_low(&self) -> impl Iterator<Item = &Label> {
+        self.labels.iter()
+    }
+
+    /// Returns an iterator over the high labels, ie the labels encoding the bit value 1.
+    pub fn iter_high(&self) -> impl Iterator<Item = Label> + '_ {
+        self.labels.iter().map(|label| label ^ self.state.delta)
+    }
+
+    /// Verifies that the active labels are authentic.
+    ///
+    /// The comparison is performed in constant-time so as to not leak which of the
+    /// two labels, if any, each active label matched.
     pub(crate) fn verify(&self, active: &Labels<N, state::Active>) -> Result<(), ValueError> {
+        let mut valid = Choice::from(1);
         for (low, active) in self.labels.iter().zip(active.labels.iter()) {
             let high = low ^ self.state.delta;
-            if !(active == low || active == &high) {
-                return Err(ValueError::InvalidActiveEncoding);
-            }
+            valid &= active.ct_eq(low) | active.ct_eq(&high);
         }
 
-        Ok(())
+        if bool::from(valid) {
+            Ok(())
+        } else {
+            Err(ValueError::InvalidActiveEncoding)
+        }
     }
 
     pub(crate) fn iter_blocks(&self) -> impl Iterator<Item = [Block; 2]> + '_ {
@@ -167,22 +273,25 @@ impl<const N: usize> Labels<N, state::Full> {
 }
 
 impl<const N: usize> Labels<N, state::Active> {
-    pub(crate) fn new(labels: [Label; N]) -> Self {
+    /// Creates a new collection of active labels.
+    pub fn new(labels: [Label; N]) -> Self {
         Self {
             state: state::Active,
             labels: Arc::new(labels),
         }
     }
+
+    /// Returns an iterator over the active labels.
+    pub fn iter_active(&self) -> impl Iterator<Item = &Label> {
+        self.labels.iter()
+    }
 }
 
 impl<const N: usize> BitXor for Labels<N, state::Full> {
     type Output = Labels<N, state::Full>;
 
     fn bitxor(self, rhs: Self) -> Labels<N, state::Full> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.xor_in_place(&rhs)
     }
 }
 
@@ -190,10 +299,7 @@ impl<const N: usize> BitXor for &Labels<N, state::Full> {
     type Output = Labels<N, state::Full>;
 
     fn bitxor(self, rhs: Self) -> Labels<N, state::Full> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.clone().xor_in_place(rhs)
     }
 }
 
@@ -201,10 +307,7 @@ impl<const N: usize> BitXor<&Self> for Labels<N, state::Full> {
     type Output = Labels<N, state::Full>;
 
     fn bitxor(self, rhs: &Self) -> Labels<N, state::Full> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.xor_in_place(rhs)
     }
 }
 
@@ -212,10 +315,7 @@ impl<const N: usize> BitXor<Labels<N, state::Full>> for &Labels<N, state::Full>
     type Output = Labels<N, state::Full>;
 
     fn bitxor(self, rhs: Labels<N, state::Full>) -> Labels<N, state::Full> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.clone().xor_in_place(&rhs)
     }
 }
 
@@ -223,10 +323,7 @@ impl<const N: usize> BitXor for Labels<N, state::Active> {
     type Output = Labels<N, state::Active>;
 
     fn bitxor(self, rhs: Self) -> Labels<N, state::Active> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.xor_in_place(&rhs)
     }
 }
 
@@ -234,10 +331,7 @@ impl<const N: usize> BitXor for &Labels<N, state::Active> {
     type Output = Labels<N, state::Active>;
 
     fn bitxor(self, rhs: Self) -> Labels<N, state::Active> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.clone().xor_in_place(rhs)
     }
 }
 
@@ -245,10 +339,7 @@ impl<const N: usize> BitXor<&Self> for Labels<N, state::Active> {
     type Output = Labels<N, state::Active>;
 
     fn bitxor(self, rhs: &Self) -> Labels<N, state::Active> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.xor_in_place(rhs)
     }
 }
 
@@ -256,10 +347,7 @@ impl<const N: usize> BitXor<Labels<N, state::Active>> for &Labels<N, state::Acti
     type Output = Labels<N, state::Active>;
 
     fn bitxor(self, rhs: Labels<N, state::Active>) -> Labels<N, state::Active> {
-        Labels {
-            state: self.state,
-            labels: Arc::new(std::array::from_fn(|i| self.labels[i] ^ rhs.labels[i])),
-        }
+        self.clone().xor_in_place(&rhs)
     }
 }
 
@@ -285,12 +373,18 @@ impl Label {
         Self(value)
     }
 
-    /// Returns inner block
+    /// Consumes the label, returning the inner block
     #[inline]
-    pub(crate) fn to_inner(self) -> Block {
+    pub fn into_block(self) -> Block {
         self.0
     }
 
+    /// Returns a reference to the inner block
+    #[inline]
+    pub fn as_block(&self) -> &Block {
+        &self.0
+    }
+
     /// Returns label pointer bit from the Point-and-Permute technique
     #[inline]
     pub(crate) fn pointer_bit(&self) -> bool {
@@ -350,6 +444,13 @@ impl BitXor<Delta> for &Label {
     }
 }
 
+impl ConstantTimeEq for Label {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
 impl AsRef<Block> for Label {
     fn as_ref(&self) -> &Block {
         &self.0
@@ -361,3 +462,156 @@ impl From<Block> for Label {
         Self(block)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha12Rng;
+
+    use super::*;
+
+    fn full_labels<const N: usize>(rng: &mut ChaCha12Rng) -> Labels<N, state::Full> {
+        let delta = Delta::random(rng);
+        Labels::<N, state::Full>::new(delta, std::array::from_fn(|_| Label::random(rng)))
+    }
+
+    #[test]
+    fn test_delta_new_sets_lsb() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        for block in [Block::ZERO, Block::ONES, Block::random(&mut rng)] {
+            let delta = Delta::new(block);
+            assert_eq!(delta.as_block().lsb(), 1);
+
+            let mut expected = block;
+            expected.set_lsb();
+            assert_eq!(*delta.as_block(), expected);
+        }
+    }
+
+    #[test]
+    fn test_label_block_round_trip() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let block = Block::random(&mut rng);
+        let label = Label::from(block);
+
+        assert_eq!(*label.as_block(), block);
+        assert_eq!(label.into_block(), block);
+    }
+
+    #[test]
+    fn test_label_ct_eq() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let a = Label::random(&mut rng);
+        let b = Label::random(&mut rng);
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+    }
+
+    #[test]
+    fn test_labels_iter() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let full = full_labels::<8>(&mut rng);
+        let delta = full.delta();
+
+        assert!(full.iter_low().eq(full.iter()));
+        assert!(full
+            .iter_high()
+            .zip(full.iter_low())
+            .all(|(high, low)| high == low ^ delta));
+
+        let active = Labels::<8, state::Active>::new(*full.labels);
+        assert!(active.iter_active().eq(full.iter_low()));
+    }
+
+    #[test]
+    fn test_labels_xor() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let a = full_labels::<1024>(&mut rng);
+        let b = full_labels::<1024>(&mut rng);
+        let original = *a.labels;
+
+        let expected: Vec<Label> = a.iter().zip(b.iter()).map(|(a, b)| a ^ b).collect();
+
+        let c = &a ^ &b;
+        assert!(c.iter().eq(expected.iter()));
+        assert_eq!(c.delta(), a.delta());
+        assert!((&a ^ b.clone()).iter().eq(expected.iter()));
+        assert!((a.clone() ^ &b).iter().eq(expected.iter()));
+        assert!((a.clone() ^ b.clone()).iter().eq(expected.iter()));
+
+        // `a` shares its labels with the clones above, so it must not have been modified.
+        assert_eq!(*a.labels, original);
+
+        let a = Labels::<1024, state::Active>::new(*a.labels);
+        let b = Labels::<1024, state::Active>::new(*b.labels);
+
+        assert!((&a ^ &b).iter().eq(expected.iter()));
+        assert!((a ^ b).iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn test_labels_split_concat() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let full = full_labels::<8>(&mut rng);
+
+        let (a, b) = full.split::<3, 5>();
+        assert!(a.iter().chain(b.iter()).eq(full.iter()));
+        assert_eq!(a.delta(), full.delta());
+        assert_eq!(b.delta(), full.delta());
+
+        let joined = Labels::<8, state::Full>::concat(&a, &b).unwrap();
+        assert_eq!(joined, full);
+
+        let active = Labels::<8, state::Active>::new(*full.labels);
+        let (a, b) = active.split::<8, 0>();
+        assert_eq!(Labels::<8, state::Active>::concat(&a, &b).unwrap(), active);
+    }
+
+    #[test]
+    fn test_labels_concat_delta_mismatch() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let a = full_labels::<4>(&mut rng);
+        let b = full_labels::<4>(&mut rng);
+
+        let err = Labels::<8, state::Full>::concat(&a, &b).unwrap_err();
+
+        assert!(matches!(err, ValueError::StateMismatch));
+    }
+
+    #[test]
+    fn test_labels_verify() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let full = full_labels::<8>(&mut rng);
+        let delta = full.delta();
+
+        let low = Labels::<8, state::Active>::new(*full.labels);
+        let high = Labels::<8, state::Active>::new(full.labels.map(|label| label ^ delta));
+        let mixed = Labels::<8, state::Active>::new(std::array::from_fn(|i| {
+            if i % 2 == 0 {
+                full[i]
+            } else {
+                full[i] ^ delta
+            }
+        }));
+
+        full.verify(&low).unwrap();
+        full.verify(&high).unwrap();
+        full.verify(&mixed).unwrap();
+    }
+
+    #[test]
+    fn test_labels_verify_rejects_invalid() {
+        let mut rng = ChaCha12Rng::seed_from_u64(0);
+        let full = full_labels::<8>(&mut rng);
+
+        let mut labels = *full.labels;
+        labels[3] = Label::random(&mut rng);
+        let invalid = Labels::<8, state::Active>::new(labels);
+
+        assert!(matches!(
+            full.verify(&invalid),
+            Err(ValueError::InvalidActiveEncoding)
+        ));
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::BitXor;
 use mpz_circuits::types::TypeError;
 
 use crate::{
+    encoding::LabelState,
     encoding_state::{Active, Full},
     EncodedValue, ValueError,
 };
@@ -13,120 +14,85 @@ macro_rules! impl_encoded_xor {
             type Output = Result<EncodedValue<$state>, ValueError>;
 
             fn bitxor(self, rhs: Self) -> Self::Output {
-                match (&self, &rhs) {
-                    (EncodedValue::Bit(a), EncodedValue::Bit(b)) => Ok(EncodedValue::Bit(a ^ b)),
-                    (EncodedValue::U8(a), EncodedValue::U8(b)) => Ok(EncodedValue::U8(a ^ b)),
-                    (EncodedValue::U16(a), EncodedValue::U16(b)) => Ok(EncodedValue::U16(a ^ b)),
-                    (EncodedValue::U32(a), EncodedValue::U32(b)) => Ok(EncodedValue::U32(a ^ b)),
-                    (EncodedValue::U64(a), EncodedValue::U64(b)) => Ok(EncodedValue::U64(a ^ b)),
-                    (EncodedValue::U128(a), EncodedValue::U128(b)) => Ok(EncodedValue::U128(a ^ b)),
-                    (EncodedValue::Array(a), EncodedValue::Array(b))
-                        if self.value_type() == rhs.value_type() =>
-                    {
-                        Ok(EncodedValue::Array(
-                            a.into_iter()
-                                .zip(b.into_iter())
-                                .map(|(a, b)| a ^ b)
-                                .collect::<Result<Vec<_>, _>>()?,
-                        ))
-                    }
-                    _ => Err(ValueError::TypeError(TypeError::UnexpectedType {
-                        expected: self.value_type(),
-                        actual: rhs.value_type(),
-                    })),
-                }
+                self ^ &rhs
             }
         }
 
-        impl BitXor for &EncodedValue<$state> {
+        impl BitXor<EncodedValue<$state>> for &EncodedValue<$state> {
             type Output = Result<EncodedValue<$state>, ValueError>;
 
-            fn bitxor(self, rhs: Self) -> Self::Output {
-                match (&self, &rhs) {
-                    (EncodedValue::Bit(a), EncodedValue::Bit(b)) => Ok(EncodedValue::Bit(a ^ b)),
-                    (EncodedValue::U8(a), EncodedValue::U8(b)) => Ok(EncodedValue::U8(a ^ b)),
-                    (EncodedValue::U16(a), EncodedValue::U16(b)) => Ok(EncodedValue::U16(a ^ b)),
-                    (EncodedValue::U32(a), EncodedValue::U32(b)) => Ok(EncodedValue::U32(a ^ b)),
-                    (EncodedValue::U64(a), EncodedValue::U64(b)) => Ok(EncodedValue::U64(a ^ b)),
-                    (EncodedValue::U128(a), EncodedValue::U128(b)) => Ok(EncodedValue::U128(a ^ b)),
-                    (EncodedValue::Array(a), EncodedValue::Array(b))
-                        if self.value_type() == rhs.value_type() =>
-                    {
-                        Ok(EncodedValue::Array(
-                            a.into_iter()
-                                .zip(b.into_iter())
-                                .map(|(a, b)| a ^ b)
-                                .collect::<Result<Vec<_>, _>>()?,
-                        ))
-                    }
-                    _ => Err(ValueError::TypeError(TypeError::UnexpectedType {
-                        expected: self.value_type(),
-                        actual: rhs.value_type(),
-                    })),
-                }
+            fn bitxor(self, rhs: EncodedValue<$state>) -> Self::Output {
+                self ^ &rhs
             }
         }
 
-        impl BitXor<EncodedValue<$state>> for &EncodedValue<$state> {
+        impl BitXor<&EncodedValue<$state>> for EncodedValue<$state> {
             type Output = Result<EncodedValue<$state>, ValueError>;
 
-            fn bitxor(self, rhs: EncodedValue<$state>) -> Self::Output {
-                match (&self, &rhs) {
+            fn bitxor(self, rhs: &EncodedValue<$state>) -> Self::Output {
+                check_value_types(&self, rhs)?;
+
+                // The lhs is consumed so that its labels are XORed in place.
+                match (self, rhs) {
                     (EncodedValue::Bit(a), EncodedValue::Bit(b)) => Ok(EncodedValue::Bit(a ^ b)),
                     (EncodedValue::U8(a), EncodedValue::U8(b)) => Ok(EncodedValue::U8(a ^ b)),
                     (EncodedValue::U16(a), EncodedValue::U16(b)) => Ok(EncodedValue::U16(a ^ b)),
                     (EncodedValue::U32(a), EncodedValue::U32(b)) => Ok(EncodedValue::U32(a ^ b)),
                     (EncodedValue::U64(a), EncodedValue::U64(b)) => Ok(EncodedValue::U64(a ^ b)),
                     (EncodedValue::U128(a), EncodedValue::U128(b)) => Ok(EncodedValue::U128(a ^ b)),
-                    (EncodedValue::Array(a), EncodedValue::Array(b))
-                        if self.value_type() == rhs.value_type() =>
-                    {
-                        Ok(EncodedValue::Array(
-                            a.into_iter()
-                                .zip(b.into_iter())
-                                .map(|(a, b)| a ^ b)
-                                .collect::<Result<Vec<_>, _>>()?,
-                        ))
-                    }
-                    _ => Err(ValueError::TypeError(TypeError::UnexpectedType {
-                        expected: self.value_type(),
-                        actual: rhs.value_type(),
-                    })),
+                    (EncodedValue::Array(a), EncodedValue::Array(b)) => Ok(EncodedValue::Array(
+                        a.into_iter()
+                            .zip(b)
+                            .map(|(a, b)| a ^ b)
+                            .collect::<Result<Vec<_>, _>>()?,
+                    )),
+                    _ => unreachable!("value types are checked to match"),
                 }
             }
         }
 
-        impl BitXor<&EncodedValue<$state>> for EncodedValue<$state> {
+        impl BitXor for &EncodedValue<$state> {
             type Output = Result<EncodedValue<$state>, ValueError>;
 
-            fn bitxor(self, rhs: &EncodedValue<$state>) -> Self::Output {
-                match (&self, &rhs) {
+            fn bitxor(self, rhs: Self) -> Self::Output {
+                check_value_types(self, rhs)?;
+
+                match (self, rhs) {
                     (EncodedValue::Bit(a), EncodedValue::Bit(b)) => Ok(EncodedValue::Bit(a ^ b)),
                     (EncodedValue::U8(a), EncodedValue::U8(b)) => Ok(EncodedValue::U8(a ^ b)),
                     (EncodedValue::U16(a), EncodedValue::U16(b)) => Ok(EncodedValue::U16(a ^ b)),
                     (EncodedValue::U32(a), EncodedValue::U32(b)) => Ok(EncodedValue::U32(a ^ b)),
                     (EncodedValue::U64(a), EncodedValue::U64(b)) => Ok(EncodedValue::U64(a ^ b)),
                     (EncodedValue::U128(a), EncodedValue::U128(b)) => Ok(EncodedValue::U128(a ^ b)),
-                    (EncodedValue::Array(a), EncodedValue::Array(b))
-                        if self.value_type() == rhs.value_type() =>
-                    {
-                        Ok(EncodedValue::Array(
-                            a.into_iter()
-                                .zip(b.into_iter())
-                                .map(|(a, b)| a ^ b)
-                                .collect::<Result<Vec<_>, _>>()?,
-                        ))
-                    }
-                    _ => Err(ValueError::TypeError(TypeError::UnexpectedType {
-                        expected: self.value_type(),
-                        actual: rhs.value_type(),
-                    })),
+                    (EncodedValue::Array(a), EncodedValue::Array(b)) => Ok(EncodedValue::Array(
+                        a.iter()
+                            .zip(b)
+                            .map(|(a, b)| a ^ b)
+                            .collect::<Result<Vec<_>, _>>()?,
+                    )),
+                    _ => unreachable!("value types are checked to match"),
                 }
             }
         }
     };
 }
 
+/// Checks that both operands of an XOR have the same value type.
+fn check_value_types<S: LabelState>(
+    lhs: &EncodedValue<S>,
+    rhs: &EncodedValue<S>,
+) -> Result<(), ValueError> {
+    let (expected, actual) = (lhs.value_type(), rhs.value_type());
+    if expected != actual {
+        return Err(ValueError::TypeError(TypeError::UnexpectedType {
+            expected,
+            actual,
+        }));
+    }
+
+    Ok(())
+}
+
 impl_encoded_xor!(Active);
 impl_encoded_xor!(Full);
 
@@ -216,4 +182,23 @@ mod tests {
 
         assert_eq!(c, expected_c);
     }
+
+    #[rstest]
+    fn test_encoded_xor_array_type_mismatch(encoder: ChaChaEncoder) {
+        let a: EncodedValue<_> = encoder.encode_by_type(0, &<[u8; 16]>::value_type());
+        let b: EncodedValue<_> = encoder.encode_by_type(1, &<[u16; 16]>::value_type());
+
+        let is_mismatch = |err: ValueError| {
+            matches!(
+                err,
+                ValueError::TypeError(TypeError::UnexpectedType { expected, actual })
+                    if expected == <[u8; 16]>::value_type() && actual == <[u16; 16]>::value_type()
+            )
+        };
+
+        assert!(is_mismatch((&a ^ &b).unwrap_err()));
+        assert!(is_mismatch((&a ^ b.clone()).unwrap_err()));
+        assert!(is_mismatch((a.clone() ^ &b).unwrap_err()));
+        assert!(is_mismatch((a ^ b).unwrap_err()));
+    }
 }
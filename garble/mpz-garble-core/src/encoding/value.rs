@@ -20,6 +20,8 @@ pub enum ValueError {
     InvalidActiveEncoding,
     #[error("invalid commitment")]
     InvalidCommitment,
+    #[error("labels are not in the same state")]
+    StateMismatch,
 }
 
 /// A trait for encoding values.
@@ -34,8 +34,8 @@ pub(crate) fn and_gate(
     encrypted_gate: &EncryptedGate,
     gid: usize,
 ) -> Label {
-    let x = x.to_inner();
-    let y = y.to_inner();
+    let x = x.into_block();
+    let y = y.into_block();
 
     let s_a = x.lsb();
     let s_b = y.lsb();
@@ -35,9 +35,9 @@ pub(crate) fn and_gate(
     gid: usize,
 ) -> (Label, EncryptedGate) {
     let delta = delta.into_inner();
-    let x_0 = x_0.to_inner();
+    let x_0 = x_0.into_block();
     let x_1 = x_0 ^ delta;
-    let y_0 = y_0.to_inner();
+    let y_0 = y_0.into_block();
     let y_1 = y_0 ^ delta;
 
     let p_a = x_0.lsb();
@@ -8,6 +8,20 @@ use crate::{
     EqualityCheck,
 };
 
+/// Version of the garbled circuit message protocol.
+///
+/// Builds with different versions can not interoperate, and are rejected during the handshake.
+pub const PROTOCOL_VERSION: u32 = 1;
+
+/// Handshake message sent by the generator before any other message.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Hello {
+    /// The protocol version of the generator.
+    pub version: u32,
+    /// Optional protocol features enabled on the generator.
+    pub features: Vec<String>,
+}
+
 /// Top-level message type encapsulating all messages used in garbled circuit protocols.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -26,4 +40,5 @@ pub enum GarbleMessage {
     ProofDecommitments(Vec<Decommitment<Hash>>),
     Delta(Delta),
     EncoderSeed(Vec<u8>),
+    Hello(Hello),
 }
@@ -12,6 +12,9 @@ pub struct EvaluatorConfig {
     /// Whether to log decodings.
     #[builder(default = "false", setter(custom))]
     pub(crate) log_decodings: bool,
+    /// Optional protocol features the generator is allowed to advertise during the handshake.
+    #[builder(default)]
+    pub(crate) features: Vec<String>,
 }
 
 impl EvaluatorConfig {
@@ -25,6 +25,10 @@ pub enum EvaluatorError {
     DuplicateCircuit,
     #[error("duplicate decoding for value: {0:?}")]
     DuplicateDecoding(ValueId),
+    #[error("protocol version mismatch: expected {expected}, got {actual}")]
+    VersionMismatch { expected: u32, actual: u32 },
+    #[error("generator advertised unsupported feature: {0}")]
+    UnsupportedFeature(String),
     #[error(transparent)]
     VerificationError(#[from] VerificationError),
 }
@@ -16,8 +16,9 @@ use mpz_circuits::{
 };
 use mpz_core::hash::Hash;
 use mpz_garble_core::{
-    encoding_state, msg::GarbleMessage, Decoding, EncodedValue, Evaluator as EvaluatorCore,
-    GarbledCircuit,
+    encoding_state,
+    msg::{GarbleMessage, Hello, PROTOCOL_VERSION},
+    Decoding, EncodedValue, Evaluator as EvaluatorCore, GarbledCircuit,
 };
 use utils::iter::FilterDrain;
 use utils_aio::{
@@ -106,6 +107,37 @@ impl Evaluator {
         Ok(())
     }
 
+    /// Receives the generator's protocol version and features, rejecting the generator
+    /// if either is not supported.
+    ///
+    /// This must be the first message received from the generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `stream` - The stream from the generator
+    pub async fn handshake<S: Stream<Item = Result<GarbleMessage, std::io::Error>> + Unpin>(
+        &self,
+        stream: &mut S,
+    ) -> Result<(), EvaluatorError> {
+        let Hello { version, features } = expect_msg_or_err!(stream, GarbleMessage::Hello)?;
+
+        if version != PROTOCOL_VERSION {
+            return Err(EvaluatorError::VersionMismatch {
+                expected: PROTOCOL_VERSION,
+                actual: version,
+            });
+        }
+
+        if let Some(feature) = features
+            .into_iter()
+            .find(|feature| !self.config.features.contains(feature))
+        {
+            return Err(EvaluatorError::UnsupportedFeature(feature));
+        }
+
+        Ok(())
+    }
+
     /// Returns the encoding for a value.
     pub fn get_encoding(&self, value: &ValueRef) -> Option<EncodedValue<encoding_state::Active>> {
         self.state().memory.get_encoding(value)
@@ -9,6 +9,9 @@ pub struct GeneratorConfig {
     /// The batch size for encrypted gates sent to the evaluator.
     #[builder(default = "1024")]
     pub(crate) batch_size: usize,
+    /// Optional protocol features advertised to the evaluator during the handshake.
+    #[builder(default)]
+    pub(crate) features: Vec<String>,
 }
 
 impl GeneratorConfig {
@@ -16,8 +16,9 @@ use mpz_circuits::{
 };
 use mpz_core::hash::Hash;
 use mpz_garble_core::{
-    encoding_state, msg::GarbleMessage, ChaChaEncoder, EncodedValue, Encoder,
-    Generator as GeneratorCore,
+    encoding_state,
+    msg::{GarbleMessage, Hello, PROTOCOL_VERSION},
+    ChaChaEncoder, EncodedValue, Encoder, Generator as GeneratorCore,
 };
 use utils_aio::non_blocking_backend::{Backend, NonBlockingBackend};
 
@@ -76,6 +77,26 @@ impl Generator {
         self.state().encoder.seed()
     }
 
+    /// Sends the protocol version and the enabled features to the evaluator.
+    ///
+    /// This must be the first message sent to the evaluator.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - The sink to send the handshake to the evaluator
+    pub async fn handshake<S: Sink<GarbleMessage, Error = std::io::Error> + Unpin>(
+        &self,
+        sink: &mut S,
+    ) -> Result<(), GeneratorError> {
+        sink.send(GarbleMessage::Hello(Hello {
+            version: PROTOCOL_VERSION,
+            features: self.config.features.clone(),
+        }))
+        .await?;
+
+        Ok(())
+    }
+
     /// Returns the encoding for a value.
     pub fn get_encoding(&self, value: &ValueRef) -> Option<EncodedValue<encoding_state::Full>> {
         self.state().memory.get_encoding(value)
@@ -126,7 +126,10 @@ mod tests {
 
     use mpz_circuits::circuits::AES128;
     use mpz_garble_core::{ChaChaEncoder, Encoder};
-    use mpz_ot::mock::mock_ot_shared_pair;
+    use mpz_ot::{
+        mock::mock_ot_shared_pair,
+        replay::{OTLog, RecordingOt, ReplayOt},
+    };
 
     #[tokio::test]
     async fn test_encoding_transfer() {
@@ -152,4 +155,32 @@ mod tests {
 
         assert_eq!(received, expected);
     }
+
+    #[tokio::test]
+    async fn test_encoding_transfer_replay() {
+        let encoder = ChaChaEncoder::new([0u8; 32]);
+        let (sender, receiver) = mock_ot_shared_pair();
+        let sender = RecordingOt::new(sender, OTLog::<Block>::new());
+        let receiver = RecordingOt::new(receiver, OTLog::<Block>::new());
+
+        let inputs = AES128
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(id, value)| encoder.encode_by_type(id as u64, &value.value_type()))
+            .collect::<Vec<_>>();
+        let choices = vec![Value::from([42u8; 16]), Value::from([69u8; 16])];
+
+        sender.send("", inputs.clone()).await.unwrap();
+        let received = receiver.receive("", choices.clone()).await.unwrap();
+
+        let sender = ReplayOt::new(sender.log().clone());
+        let receiver = ReplayOt::new(receiver.log().clone());
+
+        sender.send("", inputs.clone()).await.unwrap();
+        let replayed = receiver.receive("", choices).await.unwrap();
+
+        assert_eq!(replayed, received);
+        receiver.verify("", inputs).await.unwrap();
+    }
 }
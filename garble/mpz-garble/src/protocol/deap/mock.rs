@@ -30,7 +30,7 @@ pub async fn create_mock_deap_vm(
     let leader_channel = mux_factory.get_channel(id).await.unwrap();
     let follower_channel = mux_factory.get_channel(id).await.unwrap();
 
-    let leader = DEAPVm::new(
+    let mut leader = DEAPVm::new(
         id,
         Role::Leader,
         [42u8; 32],
@@ -40,7 +40,7 @@ pub async fn create_mock_deap_vm(
         leader_ot_recv,
     );
 
-    let follower = DEAPVm::new(
+    let mut follower = DEAPVm::new(
         id,
         Role::Follower,
         [69u8; 32],
@@ -50,5 +50,7 @@ pub async fn create_mock_deap_vm(
         follower_ot_recv,
     );
 
+    futures::try_join!(leader.handshake(), follower.handshake()).unwrap();
+
     (leader, follower)
 }
@@ -126,6 +126,28 @@ impl DEAP {
         self.state.lock().unwrap()
     }
 
+    /// Performs the protocol handshake with the peer.
+    ///
+    /// Each party's generator sends its protocol version and features, which are checked
+    /// by the peer's evaluator. This must be called before any other message is exchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `sink` - The sink to send messages to.
+    /// * `stream` - The stream to receive messages from.
+    pub async fn handshake<T, U>(&self, sink: &mut T, stream: &mut U) -> Result<(), DEAPError>
+    where
+        T: Sink<GarbleMessage, Error = std::io::Error> + Unpin,
+        U: Stream<Item = Result<GarbleMessage, std::io::Error>> + Unpin,
+    {
+        futures::try_join!(
+            self.gen.handshake(sink).map_err(DEAPError::from),
+            self.ev.handshake(stream).map_err(DEAPError::from)
+        )?;
+
+        Ok(())
+    }
+
     /// Performs pre-processing for executing the provided circuit.
     ///
     /// # Arguments
@@ -90,6 +90,17 @@ where
         }
     }
 
+    /// Performs the protocol handshake with the peer.
+    ///
+    /// This must be called before any threads are used.
+    pub async fn handshake(&mut self) -> Result<(), DEAPError> {
+        self.deap
+            .as_ref()
+            .expect("instance set until finalization")
+            .handshake(&mut self.sink, &mut self.stream)
+            .await
+    }
+
     /// Finalizes the DEAP instance.
     ///
     /// If this instance is the leader this function returns the follower's
@@ -0,0 +1,76 @@
+use mpz_garble_core::msg::{GarbleMessage, Hello, PROTOCOL_VERSION};
+use utils_aio::duplex::MemoryDuplex;
+
+use mpz_garble::{
+    Evaluator, EvaluatorConfigBuilder, EvaluatorError, Generator, GeneratorConfigBuilder,
+};
+
+#[tokio::test]
+async fn test_handshake() {
+    let (mut gen_channel, mut ev_channel) = MemoryDuplex::<GarbleMessage>::new();
+
+    let gen = Generator::new(
+        GeneratorConfigBuilder::default()
+            .features(vec!["foo".to_string()])
+            .build()
+            .unwrap(),
+        [0u8; 32],
+    );
+    let ev = Evaluator::new(
+        EvaluatorConfigBuilder::default()
+            .features(vec!["foo".to_string(), "bar".to_string()])
+            .build()
+            .unwrap(),
+    );
+
+    let (gen_result, ev_result) = tokio::join!(
+        gen.handshake(&mut gen_channel),
+        ev.handshake(&mut ev_channel)
+    );
+
+    gen_result.unwrap();
+    ev_result.unwrap();
+}
+
+#[tokio::test]
+async fn test_handshake_unsupported_feature() {
+    let (mut gen_channel, mut ev_channel) = MemoryDuplex::<GarbleMessage>::new();
+
+    let gen = Generator::new(
+        GeneratorConfigBuilder::default()
+            .features(vec!["foo".to_string()])
+            .build()
+            .unwrap(),
+        [0u8; 32],
+    );
+    let ev = Evaluator::default();
+
+    let (gen_result, ev_result) = tokio::join!(
+        gen.handshake(&mut gen_channel),
+        ev.handshake(&mut ev_channel)
+    );
+
+    gen_result.unwrap();
+    assert!(matches!(
+        ev_result.unwrap_err(),
+        EvaluatorError::UnsupportedFeature(feature) if feature == "foo"
+    ));
+}
+
+#[tokio::test]
+async fn test_handshake_version_mismatch() {
+    let ev = Evaluator::default();
+
+    let mut stream = futures::stream::iter([Ok(GarbleMessage::Hello(Hello {
+        version: PROTOCOL_VERSION + 1,
+        features: Vec::new(),
+    }))]);
+
+    let err = ev.handshake(&mut stream).await.unwrap_err();
+
+    assert!(matches!(
+        err,
+        EvaluatorError::VersionMismatch { expected, actual }
+            if expected == PROTOCOL_VERSION && actual == PROTOCOL_VERSION + 1
+    ));
+}
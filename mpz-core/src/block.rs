@@ -8,6 +8,7 @@ use itybity::{BitIterable, BitLength, GetBit, Lsb0, Msb0};
 use rand::{distributions::Standard, prelude::Distribution, CryptoRng, Rng};
 use serde::{Deserialize, Serialize};
 use std::convert::From;
+use subtle::{Choice, ConstantTimeEq};
 
 /// A block of 128 bits
 #[repr(transparent)]
@@ -283,6 +284,13 @@ impl BitAndAssign for Block {
     }
 }
 
+impl ConstantTimeEq for Block {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0[..].ct_eq(&other.0[..])
+    }
+}
+
 impl Distribution<Block> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Block {
         Block::new(rng.gen())
@@ -344,6 +352,16 @@ mod tests {
         assert_eq!(a.lsb(), 1);
     }
 
+    #[test]
+    fn test_ct_eq() {
+        let a = Block::new([42; 16]);
+        let mut b = a;
+        b.set_lsb();
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+    }
+
     #[test]
     fn test_reverse_bits() {
         let a = Block::new([42; 16]);
@@ -10,6 +10,7 @@ pub mod chou_orlandi;
 pub mod kos;
 #[cfg(feature = "mock")]
 pub mod mock;
+pub mod replay;
 
 use async_trait::async_trait;
 use mpz_core::ProtocolMessage;
@@ -0,0 +1,295 @@
+//! Recording and replaying of oblivious transfers for deterministic debugging.
+//!
+//! [`RecordingOt`] wraps a shared OT sender or receiver, mock or real, and logs every transfer
+//! into an [`OTLog`]. A [`ReplayOt`] created from that log plays the transfers back without
+//! running an OT protocol, so a failing run can be reproduced with exactly the same choices
+//! and messages.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+
+use crate::{
+    CommittedOTSenderShared, OTError, OTReceiverShared, OTSenderShared, VerifiableOTReceiverShared,
+};
+
+/// Errors that can occur when replaying oblivious transfers.
+#[derive(Debug, thiserror::Error)]
+#[allow(missing_docs)]
+pub enum ReplayError {
+    #[error("no recorded transfer with id: {0}")]
+    MissingTransfer(String),
+    #[error("messages do not match the recorded transfer with id: {0}")]
+    MessagesMismatch(String),
+    #[error("choices do not match the recorded transfer with id: {0}")]
+    ChoicesMismatch(String),
+}
+
+/// A log of oblivious transfers, keyed by transfer id.
+///
+/// Clones share the same underlying log.
+#[derive(Debug)]
+pub struct OTLog<T> {
+    inner: Arc<Mutex<LogInner<T>>>,
+}
+
+#[derive(Debug)]
+struct LogInner<T> {
+    /// Messages sent by the sender.
+    sent: HashMap<String, Vec<[T; 2]>>,
+    /// Choices made by the receiver, and the messages it received.
+    received: HashMap<String, (Vec<bool>, Vec<T>)>,
+}
+
+impl<T> OTLog<T> {
+    /// Creates a new, empty log.
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Mutex::new(LogInner {
+                sent: HashMap::new(),
+                received: HashMap::new(),
+            })),
+        }
+    }
+}
+
+impl<T: Clone> OTLog<T> {
+    /// Returns the messages sent in the transfer with the given id, if it was recorded.
+    pub fn sent(&self, id: &str) -> Option<Vec<[T; 2]>> {
+        self.inner.lock().unwrap().sent.get(id).cloned()
+    }
+
+    /// Returns the receiver's choices and the messages it received in the transfer with the
+    /// given id, if it was recorded.
+    pub fn received(&self, id: &str) -> Option<(Vec<bool>, Vec<T>)> {
+        self.inner.lock().unwrap().received.get(id).cloned()
+    }
+}
+
+impl<T> Clone for OTLog<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T> Default for OTLog<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// An OT sender or receiver which records every transfer into an [`OTLog`].
+#[derive(Debug, Clone)]
+pub struct RecordingOt<OT, T> {
+    inner: OT,
+    log: OTLog<T>,
+}
+
+impl<OT, T> RecordingOt<OT, T> {
+    /// Creates a new recording wrapper around `inner`, logging transfers into `log`.
+    pub fn new(inner: OT, log: OTLog<T>) -> Self {
+        Self { inner, log }
+    }
+
+    /// Returns the log of recorded transfers.
+    pub fn log(&self) -> &OTLog<T> {
+        &self.log
+    }
+
+    /// Returns the wrapped OT.
+    pub fn into_inner(self) -> OT {
+        self.inner
+    }
+}
+
+#[async_trait]
+impl<OT, T> OTSenderShared<[T; 2]> for RecordingOt<OT, T>
+where
+    OT: OTSenderShared<[T; 2]> + Send + Sync,
+    T: Clone + Send + Sync + 'static,
+{
+    async fn send(&self, id: &str, msgs: &[[T; 2]]) -> Result<(), OTError> {
+        self.inner.send(id, msgs).await?;
+
+        self.log
+            .inner
+            .lock()
+            .unwrap()
+            .sent
+            .insert(id.to_string(), msgs.to_vec());
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<OT, T> CommittedOTSenderShared<[T; 2]> for RecordingOt<OT, T>
+where
+    OT: CommittedOTSenderShared<[T; 2]> + Send + Sync,
+    T: Clone + Send + Sync + 'static,
+{
+    async fn reveal(&self) -> Result<(), OTError> {
+        self.inner.reveal().await
+    }
+}
+
+#[async_trait]
+impl<OT, T> OTReceiverShared<bool, T> for RecordingOt<OT, T>
+where
+    OT: OTReceiverShared<bool, T> + Send + Sync,
+    T: Clone + Send + Sync + 'static,
+{
+    async fn receive(&self, id: &str, choices: &[bool]) -> Result<Vec<T>, OTError> {
+        let received = self.inner.receive(id, choices).await?;
+
+        self.log
+            .inner
+            .lock()
+            .unwrap()
+            .received
+            .insert(id.to_string(), (choices.to_vec(), received.clone()));
+
+        Ok(received)
+    }
+}
+
+#[async_trait]
+impl<OT, T> VerifiableOTReceiverShared<bool, T, [T; 2]> for RecordingOt<OT, T>
+where
+    OT: VerifiableOTReceiverShared<bool, T, [T; 2]> + Send + Sync,
+    T: Clone + Send + Sync + 'static,
+{
+    async fn verify(&self, id: &str, msgs: &[[T; 2]]) -> Result<(), OTError> {
+        self.inner.verify(id, msgs).await
+    }
+}
+
+/// Replays oblivious transfers recorded in an [`OTLog`] without running an OT protocol.
+///
+/// As a sender it checks that the messages match the recorded transfer. As a receiver it
+/// checks that the choices match the recorded transfer, and returns the recorded messages.
+#[derive(Debug, Clone)]
+pub struct ReplayOt<T> {
+    log: OTLog<T>,
+}
+
+impl<T> ReplayOt<T> {
+    /// Creates a new replayer from a log of recorded transfers.
+    pub fn new(log: OTLog<T>) -> Self {
+        Self { log }
+    }
+}
+
+#[async_trait]
+impl<T> OTSenderShared<[T; 2]> for ReplayOt<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    async fn send(&self, id: &str, msgs: &[[T; 2]]) -> Result<(), OTError> {
+        let recorded = self.log.sent(id).ok_or_else(|| {
+            OTError::SenderError(Box::new(ReplayError::MissingTransfer(id.to_string())))
+        })?;
+
+        if recorded != msgs {
+            return Err(OTError::SenderError(Box::new(
+                ReplayError::MessagesMismatch(id.to_string()),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T> CommittedOTSenderShared<[T; 2]> for ReplayOt<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    async fn reveal(&self) -> Result<(), OTError> {
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T> OTReceiverShared<bool, T> for ReplayOt<T>
+where
+    T: Clone + Send + Sync + 'static,
+{
+    async fn receive(&self, id: &str, choices: &[bool]) -> Result<Vec<T>, OTError> {
+        let (recorded_choices, received) = self.log.received(id).ok_or_else(|| {
+            OTError::ReceiverError(Box::new(ReplayError::MissingTransfer(id.to_string())))
+        })?;
+
+        if recorded_choices != choices {
+            return Err(OTError::ReceiverError(Box::new(
+                ReplayError::ChoicesMismatch(id.to_string()),
+            )));
+        }
+
+        Ok(received)
+    }
+}
+
+#[async_trait]
+impl<T> VerifiableOTReceiverShared<bool, T, [T; 2]> for ReplayOt<T>
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    async fn verify(&self, id: &str, msgs: &[[T; 2]]) -> Result<(), OTError> {
+        let (choices, received) = self.log.received(id).ok_or_else(|| {
+            OTError::ReceiverError(Box::new(ReplayError::MissingTransfer(id.to_string())))
+        })?;
+
+        let consistent = msgs.len() == received.len()
+            && msgs
+                .iter()
+                .zip(choices)
+                .zip(received)
+                .all(|((msg, choice), received)| msg[choice as usize] == received);
+
+        if !consistent {
+            return Err(OTError::ReceiverError(Box::new(
+                ReplayError::MessagesMismatch(id.to_string()),
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "mock"))]
+mod tests {
+    use super::*;
+
+    use crate::mock::mock_ot_shared_pair;
+
+    #[tokio::test]
+    async fn test_replay_ot() {
+        let msgs = vec![[0, 1], [2, 3]];
+        let choices = vec![false, true];
+        let (sender, receiver) = mock_ot_shared_pair();
+
+        let sender = RecordingOt::new(sender, OTLog::<i32>::new());
+        let receiver = RecordingOt::new(receiver, OTLog::<i32>::new());
+
+        sender.send("", &msgs).await.unwrap();
+        let received = receiver.receive("", &choices).await.unwrap();
+
+        let sender = ReplayOt::new(sender.log().clone());
+        let receiver = ReplayOt::new(receiver.log().clone());
+
+        sender.send("", &msgs).await.unwrap();
+        assert_eq!(receiver.receive("", &choices).await.unwrap(), received);
+        receiver.verify("", &msgs).await.unwrap();
+
+        assert!(sender.send("", &[[0, 1], [2, 4]]).await.is_err());
+        assert!(receiver.receive("", &[true, true]).await.is_err());
+        assert!(receiver.verify("", &[[0, 1], [2, 4]]).await.is_err());
+        assert!(receiver.receive("missing", &choices).await.is_err());
+    }
+}